@@ -25,8 +25,12 @@ fn main() -> Result<(), WebIntelError> {
     println!("Browser launched successfully!");
     println!("WebSocket URL: {}", handle.websocket_url());
 
-    // In a real application, you would connect to the WebSocket URL here
-    // using a crate like `tungstenite` or `chrome_remote_interface`.
+    let session = handle.session()?;
+    let tab = session.new_tab()?;
+    tab.navigate("https://example.com")?;
+    tab.wait_for_navigation()?;
+    let title = tab.evaluate("document.title")?;
+    println!("Page title: {}", title);
 
     println!("Browser is running. Waiting for 5 seconds...");
     thread::sleep(Duration::from_secs(5));
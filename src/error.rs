@@ -5,6 +5,9 @@ pub enum WebIntelError {
     #[error("Browser executable not found. Please specify a path or ensure Chrome/Edge is installed.")]
     BrowserNotFound,
 
+    #[error("Explicit browser executable not found at {0}")]
+    ExplicitBrowserNotFound(std::path::PathBuf),
+
     #[error("Failed to create profile directory: {0}")]
     ProfileCreationFailure(std::io::Error),
 
@@ -14,9 +17,35 @@ pub enum WebIntelError {
     #[error("Failed to capture WebSocket URL from browser output.")]
     WebSocketUrlNotFound,
 
+    #[error(
+        "DevTools never came up with use_installed_profile set. Since Chrome 136, Chrome/Chromium \
+         refuses to enable --remote-debugging-port when --user-data-dir points at the browser's \
+         real default profile directory, which is exactly what use_installed_profile does. Drop \
+         use_installed_profile (use a fresh or ephemeral profile instead) if you need CDP access."
+    )]
+    InstalledProfileDebuggingBlocked,
+
     #[error("IO Error: {0}")]
     Io(#[from] std::io::Error),
 
     #[error("Failed to read browser output.")]
     OutputReadFailure,
+
+    #[error("Failed to fetch browser release metadata: {0}")]
+    FetchFailure(String),
+
+    #[error("Failed to download or extract browser archive: {0}")]
+    DownloadFailure(String),
+
+    #[error("Requested debug port {0} is already in use")]
+    DebugPortInUse(u16),
+
+    #[error("Could not find an available port for the DevTools debugger")]
+    NoAvailablePorts,
+
+    #[error("CDP session error: {0}")]
+    SessionFailure(String),
+
+    #[error("Timed out waiting for a response to CDP call {0}")]
+    CallTimeout(String),
 }
@@ -0,0 +1,253 @@
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tracing::{debug, info};
+
+use crate::error::WebIntelError;
+
+const LAST_KNOWN_GOOD_VERSIONS_URL: &str =
+    "https://googlechromelabs.github.io/chrome-for-testing/last-known-good-versions-with-downloads.json";
+
+/// Chrome-for-Testing release channel to resolve a build from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Stable,
+    Beta,
+    Dev,
+    Canary,
+}
+
+impl Channel {
+    fn as_key(&self) -> &'static str {
+        match self {
+            Channel::Stable => "Stable",
+            Channel::Beta => "Beta",
+            Channel::Dev => "Dev",
+            Channel::Canary => "Canary",
+        }
+    }
+}
+
+/// Which specific build to fetch: a named channel's latest known-good version,
+/// or a pinned revision string (e.g. a specific Chrome-for-Testing version).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Revision {
+    Latest(ChannelTag),
+    Pinned(String),
+}
+
+/// Newtype wrapper so `Channel` can be used inside `Revision` without
+/// losing `Copy`-free equality semantics elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelTag(pub Channel);
+
+#[derive(Debug, Deserialize)]
+struct VersionsResponse {
+    channels: std::collections::HashMap<String, ChannelInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelInfo {
+    version: String,
+    downloads: Downloads,
+}
+
+#[derive(Debug, Deserialize)]
+struct Downloads {
+    chrome: Vec<DownloadEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DownloadEntry {
+    platform: String,
+    url: String,
+}
+
+/// Downloads and caches known-good Chromium builds from the Chrome-for-Testing
+/// distribution, modeled on headless_chrome's `Fetcher`.
+///
+/// Builds are cached under `dirs::cache_dir()/web-intel-chromium/<version>/` so
+/// repeated launches reuse the extracted build instead of re-downloading it.
+#[derive(Debug)]
+pub struct Fetcher {
+    cache_root: PathBuf,
+}
+
+impl Fetcher {
+    /// Creates a fetcher rooted at the platform cache directory.
+    pub fn new() -> Result<Self, WebIntelError> {
+        let mut cache_root = dirs::cache_dir().ok_or_else(|| {
+            WebIntelError::FetchFailure("could not determine cache directory".to_string())
+        })?;
+        cache_root.push("web-intel-chromium");
+        Ok(Self { cache_root })
+    }
+
+    /// Resolves the requested revision to a concrete version and download URL,
+    /// downloading and extracting it if it isn't already cached, and returns
+    /// the path to the extracted browser executable.
+    pub fn fetch(&self, revision: &Revision) -> Result<PathBuf, WebIntelError> {
+        // A pinned version names its own cache directory, so a cache hit can be
+        // checked without a network round-trip at all. `Latest(channel)` always
+        // needs the network to find out which version is currently "latest".
+        if let Revision::Pinned(version) = revision {
+            let executable = self.cache_root.join(version).join(platform_executable_subpath());
+            if executable.exists() {
+                debug!("Using cached Chromium build at {:?}", executable);
+                return Ok(executable);
+            }
+        }
+
+        let (version, url) = self.resolve(revision)?;
+
+        let install_dir = self.cache_root.join(&version);
+        let executable = install_dir.join(platform_executable_subpath());
+
+        if executable.exists() {
+            debug!("Using cached Chromium build at {:?}", executable);
+            return Ok(executable);
+        }
+
+        info!("Downloading Chromium {} from {}", version, url);
+        std::fs::create_dir_all(&install_dir)
+            .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+
+        let bytes = download(&url)?;
+        extract_zip(&bytes, &install_dir)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Ok(meta) = std::fs::metadata(&executable) {
+                let mut perms = meta.permissions();
+                perms.set_mode(perms.mode() | 0o111);
+                let _ = std::fs::set_permissions(&executable, perms);
+            }
+        }
+
+        if !executable.exists() {
+            return Err(WebIntelError::DownloadFailure(format!(
+                "extracted archive did not contain expected executable at {:?}",
+                executable
+            )));
+        }
+
+        Ok(executable)
+    }
+
+    fn resolve(&self, revision: &Revision) -> Result<(String, String), WebIntelError> {
+        let response: VersionsResponse = ureq::get(LAST_KNOWN_GOOD_VERSIONS_URL)
+            .call()
+            .map_err(|e| WebIntelError::FetchFailure(e.to_string()))?
+            .into_json()
+            .map_err(|e| WebIntelError::FetchFailure(e.to_string()))?;
+
+        let platform = current_platform_key();
+
+        match revision {
+            Revision::Latest(ChannelTag(channel)) => {
+                let info = response.channels.get(channel.as_key()).ok_or_else(|| {
+                    WebIntelError::FetchFailure(format!(
+                        "no entry for channel {:?} in last-known-good-versions response",
+                        channel
+                    ))
+                })?;
+                let url = download_url_for_platform(&info.downloads.chrome, platform)?;
+                Ok((info.version.clone(), url))
+            }
+            Revision::Pinned(version) => {
+                for info in response.channels.values() {
+                    if &info.version == version {
+                        let url = download_url_for_platform(&info.downloads.chrome, platform)?;
+                        return Ok((info.version.clone(), url));
+                    }
+                }
+                Err(WebIntelError::FetchFailure(format!(
+                    "version {} not found among known-good channels",
+                    version
+                )))
+            }
+        }
+    }
+}
+
+fn download_url_for_platform(entries: &[DownloadEntry], platform: &str) -> Result<String, WebIntelError> {
+    entries
+        .iter()
+        .find(|e| e.platform == platform)
+        .map(|e| e.url.clone())
+        .ok_or_else(|| {
+            WebIntelError::FetchFailure(format!("no download available for platform {}", platform))
+        })
+}
+
+fn current_platform_key() -> &'static str {
+    if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+        "win64"
+    } else if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        "mac-arm64"
+    } else if cfg!(target_os = "macos") {
+        "mac-x64"
+    } else {
+        "linux64"
+    }
+}
+
+fn platform_executable_subpath() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        Path::new("chrome-win64").join("chrome.exe")
+    } else if cfg!(target_os = "macos") {
+        let dir = if cfg!(target_arch = "aarch64") { "chrome-mac-arm64" } else { "chrome-mac-x64" };
+        Path::new(dir)
+            .join("Google Chrome for Testing.app")
+            .join("Contents/MacOS/Google Chrome for Testing")
+    } else {
+        Path::new("chrome-linux64").join("chrome")
+    }
+}
+
+fn download(url: &str) -> Result<Vec<u8>, WebIntelError> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+    Ok(bytes)
+}
+
+fn extract_zip(bytes: &[u8], dest: &Path) -> Result<(), WebIntelError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+        let out_path = match entry.enclosed_name() {
+            Some(name) => dest.join(name),
+            None => continue,
+        };
+
+        if entry.is_dir() {
+            std::fs::create_dir_all(&out_path)
+                .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+        } else {
+            if let Some(parent) = out_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+            }
+            let mut out_file =
+                File::create(&out_path).map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+            std::io::copy(&mut entry, &mut out_file)
+                .map_err(|e| WebIntelError::DownloadFailure(e.to_string()))?;
+        }
+    }
+
+    Ok(())
+}
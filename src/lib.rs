@@ -1,11 +1,12 @@
+use std::net::TcpListener;
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::io::{BufRead, BufReader};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use regex::Regex;
+use serde::Deserialize;
 use tempfile::TempDir;
 use tracing::{debug, info};
 use which::which;
@@ -13,6 +14,13 @@ use which::which;
 mod error;
 pub use error::WebIntelError;
 
+mod fetcher;
+pub use fetcher::{Channel, Revision};
+use fetcher::{ChannelTag, Fetcher};
+
+mod session;
+pub use session::{CdpEvent, Session, Tab};
+
 /// Strategies for AI Execution
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AIExecutionStrategy {
@@ -53,6 +61,10 @@ pub struct BrowserLauncherBuilder {
     strategy: AIExecutionStrategy,
     openai_api_key: Option<String>,
     anthropic_api_key: Option<String>,
+    fetch_if_missing: bool,
+    revision: Revision,
+    debug_port: Option<u16>,
+    use_installed_profile: Option<Channel>,
 }
 
 impl Default for BrowserLauncherBuilder {
@@ -71,6 +83,10 @@ impl Default for BrowserLauncherBuilder {
             strategy: AIExecutionStrategy::default(),
             openai_api_key: None,
             anthropic_api_key: None,
+            fetch_if_missing: false,
+            revision: Revision::Latest(ChannelTag(Channel::Stable)),
+            debug_port: None,
+            use_installed_profile: None,
         }
     }
 }
@@ -162,9 +178,71 @@ impl BrowserLauncherBuilder {
         self
     }
 
+    /// Attaches to an already-running browser instead of spawning a new one.
+    /// See `BrowserHandle::connect` for accepted URL forms.
+    pub fn connect(url: impl AsRef<str>) -> Result<BrowserHandle, WebIntelError> {
+        BrowserHandle::connect(url)
+    }
+
+    /// If true and no local browser executable can be found, download a known-good
+    /// Chromium build from the Chrome-for-Testing distribution and use that instead
+    /// of returning `BrowserNotFound`. Downloaded builds are cached by version under
+    /// `dirs::cache_dir()/web-intel-chromium/`, so this only pays the download cost once.
+    pub fn fetch_if_missing(mut self, enabled: bool) -> Self {
+        self.fetch_if_missing = enabled;
+        self
+    }
+
+    /// Pin the Chrome-for-Testing channel to resolve the latest known-good version from
+    /// when `fetch_if_missing` is enabled. Defaults to `Channel::Stable`.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.revision = Revision::Latest(ChannelTag(channel));
+        self
+    }
+
+    /// Pin an exact Chrome-for-Testing version string to fetch, instead of resolving
+    /// the latest version of a channel. Only used when `fetch_if_missing` is enabled.
+    /// A version that is not already cached is found only if it is currently the
+    /// last-known-good version of one of the channels; otherwise `launch` returns
+    /// `WebIntelError::FetchFailure`.
+    pub fn revision(mut self, version: impl Into<String>) -> Self {
+        self.revision = Revision::Pinned(version.into());
+        self
+    }
+
+    /// Pin the DevTools debugging port instead of letting the OS assign one.
+    /// If the port is already in use, `launch` returns `WebIntelError::DebugPortInUse`.
+    pub fn debug_port(mut self, port: Option<u16>) -> Self {
+        self.debug_port = port;
+        self
+    }
+
+    /// Point `--user-data-dir` at the user's real Chrome/Edge profile directory for
+    /// `channel`, instead of creating a fresh one under the cache dir. Useful for
+    /// non-ephemeral launches that want to reuse the user's existing logins/extensions
+    /// rather than starting from a blank profile. Has no effect when `ephemeral(true)`.
+    ///
+    /// **Limitation:** since Chrome 136, Chrome/Chromium refuses to bring up the
+    /// remote-debugging port at all when `--user-data-dir` resolves to the
+    /// browser's real default profile directory — specifically to stop this
+    /// "attach CDP to a live, logged-in profile" pattern. `launch()` always waits
+    /// for that port, so against any reasonably current Chrome/Edge it fails with
+    /// `WebIntelError::InstalledProfileDebuggingBlocked` and returns no handle.
+    pub fn use_installed_profile(mut self, channel: Channel) -> Self {
+        self.use_installed_profile = Some(channel);
+        self
+    }
+
     /// Launches the browser with the configured settings.
     pub fn launch(self) -> Result<BrowserHandle, WebIntelError> {
-        let browser_path = self.find_browser_executable()?;
+        let browser_path = match self.find_browser_executable() {
+            Ok(path) => path,
+            Err(WebIntelError::BrowserNotFound) if self.fetch_if_missing => {
+                info!("No local browser found, fetching a known-good Chromium build");
+                Fetcher::new()?.fetch(&self.revision)?
+            }
+            Err(e) => return Err(e),
+        };
         debug!("Using browser executable: {:?}", browser_path);
 
         let user_data_dir = if self.ephemeral {
@@ -174,6 +252,8 @@ impl BrowserLauncherBuilder {
                     .tempdir()
                     .map_err(WebIntelError::ProfileCreationFailure)?
             )
+        } else if let Some(channel) = self.use_installed_profile {
+            UserDataDir::Persistent(installed_profile_dir(channel)?)
         } else {
             let mut path = dirs::cache_dir().ok_or_else(|| {
                 WebIntelError::ProfileCreationFailure(std::io::Error::new(
@@ -189,9 +269,11 @@ impl BrowserLauncherBuilder {
 
         let mut cmd = Command::new(browser_path);
 
+        let debug_port = resolve_debug_port(self.debug_port)?;
+
         // Basic flags
         cmd.arg(format!("--user-data-dir={}", user_data_dir.path().display()));
-        cmd.arg("--remote-debugging-port=0"); // Let the OS pick a free port
+        cmd.arg(format!("--remote-debugging-port={}", debug_port));
         cmd.arg("--no-first-run");
         cmd.arg("--no-default-browser-check");
 
@@ -244,68 +326,43 @@ impl BrowserLauncherBuilder {
         };
         cmd.env("WEB_INTEL_STRATEGY", strategy_str);
 
-        // Capture stderr to find the DevTools WebSocket URL
+        // We still capture stderr, but only to drain it so the browser never blocks
+        // on a full pipe buffer. We no longer scrape it for the WebSocket URL: stderr
+        // format differs across platforms/headless modes and isn't a stable contract.
         cmd.stderr(Stdio::piped());
         // We don't need stdout, so we discard it to avoid filling the pipe and deadlocking
         cmd.stdout(Stdio::null());
 
         let mut child = cmd.spawn().map_err(WebIntelError::LaunchFailure)?;
 
-        // Need to read stderr to find the WebSocket URL.
-        // We do this in a non-blocking way or spawn a thread?
-        // Spawning a thread to read until we find the URL or timeout seems appropriate.
-        // Since we need to return the handle, but also the URL, we might need to wait a bit.
-
         let stderr = child.stderr.take().ok_or(WebIntelError::OutputReadFailure)?;
-        let websocket_url = Arc::new(Mutex::new(None));
-        let ws_clone = websocket_url.clone();
-
-        // Spawn a thread to read stderr and extract the WS URL
         thread::spawn(move || {
             let reader = BufReader::new(stderr);
-            let re = Regex::new(r"ws://127\.0\.0\.1:\d+/devtools/browser/[\w-]+").expect("Invalid Regex");
-
             for line in reader.lines() {
                 if let Ok(l) = line {
-                    // Log output for debugging
                     debug!("[Browser]: {}", l);
-                    if let Some(caps) = re.find(&l) {
-                        let mut guard = ws_clone.lock().unwrap();
-                        *guard = Some(caps.as_str().to_string());
-                        // Once found, we can continue reading or just let it be.
-                        // Often we want to keep draining the pipe to avoid blocking.
-                    }
                 }
             }
         });
 
-        // Wait a short duration for the WS URL to appear
-        let start = std::time::Instant::now();
-        let timeout = Duration::from_secs(10);
-        let mut found_url = None;
-
-        while start.elapsed() < timeout {
-            {
-                let guard = websocket_url.lock().unwrap();
-                if let Some(ref url) = *guard {
-                    found_url = Some(url.clone());
-                    break;
-                }
-            }
-            if let Ok(Some(_status)) = child.try_wait() {
-                // Process exited early
-                return Err(WebIntelError::LaunchFailure(std::io::Error::new(std::io::ErrorKind::Other, "Browser process exited unexpectedly")));
+        let url = wait_for_websocket_url(&mut child, debug_port, Duration::from_secs(10)).map_err(|e| {
+            // A generic timeout here is almost always this specific, known-broken
+            // combination rather than a slow launch, so say so plainly instead of
+            // making the caller rediscover it via a DevTools changelog.
+            if !self.ephemeral && self.use_installed_profile.is_some() && matches!(e, WebIntelError::WebSocketUrlNotFound) {
+                WebIntelError::InstalledProfileDebuggingBlocked
+            } else {
+                e
             }
-            thread::sleep(Duration::from_millis(100));
-        }
-
-        let url = found_url.ok_or(WebIntelError::WebSocketUrlNotFound)?;
+        })?;
         info!("Browser launched. WebSocket URL: {}", url);
 
         Ok(BrowserHandle {
-            process: child,
+            process: Some(child),
             websocket_url: url,
-            _user_data_dir: user_data_dir,
+            _user_data_dir: Some(user_data_dir),
+            owns_process: true,
+            session: Mutex::new(None),
         })
     }
 
@@ -314,61 +371,300 @@ impl BrowserLauncherBuilder {
             if path.exists() {
                 return Ok(path.clone());
             }
-            return Err(WebIntelError::BrowserNotFound);
+            return Err(WebIntelError::ExplicitBrowserNotFound(path.clone()));
         }
 
-        let mut candidates = Vec::new();
-
-        if cfg!(target_os = "windows") {
-             // Standard PATH binaries
-            candidates.extend(vec!["chrome.exe".to_string(), "msedge.exe".to_string(), "chromium.exe".to_string()]);
-            
-            // Common Windows Installation Paths
-            let program_files = std::env::var("ProgramFiles").unwrap_or_else(|_| r"C:\Program Files".to_string());
-            let program_files_x86 = std::env::var("ProgramFiles(x86)").unwrap_or_else(|_| r"C:\Program Files (x86)".to_string());
-            let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| r"C:\Users\Default\AppData\Local".to_string());
-
-            candidates.push(format!(r"{}\Google\Chrome SxS\Application\chrome.exe", local_app_data)); // Canary
-            candidates.push(format!(r"{}\Google\Chrome\Application\chrome.exe", program_files));
-            candidates.push(format!(r"{}\Google\Chrome\Application\chrome.exe", program_files_x86));
-            candidates.push(format!(r"{}\Microsoft\Edge\Application\msedge.exe", program_files));
-            candidates.push(format!(r"{}\Microsoft\Edge\Application\msedge.exe", program_files_x86));
-
-        } else if cfg!(target_os = "macos") {
-            candidates.extend(vec![
-                "/Applications/Google Chrome Canary.app/Contents/MacOS/Google Chrome Canary".to_string(),
-                "/Applications/Google Chrome Dev.app/Contents/MacOS/Google Chrome Dev".to_string(),
-                "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome".to_string(),
-                "/Applications/Chromium.app/Contents/MacOS/Chromium".to_string(),
-                "/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge".to_string()
-            ]);
-        } else {
-             // Linux
-            candidates.extend(vec![
-                "google-chrome-unstable".to_string(),
-                "google-chrome-beta".to_string(),
-                "google-chrome".to_string(),
-                "google-chrome-stable".to_string(),
-                "chromium".to_string(),
-                "chromium-browser".to_string()
-            ]);
-        };
+        let candidates = browser_candidates();
+
+        // If we need to reuse a specific channel's real profile directory, the
+        // executable we launch has to be from that same channel too: Chrome
+        // refuses (or silently reinitializes) a profile stamped by a different
+        // channel/version. Search for a channel match before falling back to the
+        // overall preference order below.
+        if let Some(wanted_channel) = self.use_installed_profile.filter(|_| !self.ephemeral) {
+            for candidate in &candidates {
+                if candidate.channel == wanted_channel {
+                    if let Some(path) = candidate.resolve() {
+                        return Ok(path);
+                    }
+                }
+            }
+        }
+
+        for candidate in &candidates {
+            if let Some(path) = candidate.resolve() {
+                return Ok(path);
+            }
+        }
+
+        Err(WebIntelError::BrowserNotFound)
+    }
+}
+
+/// A single place to look for a browser executable, in the preference order
+/// `browser_candidates()` returns them in.
+enum Probe {
+    /// A literal path (absolute) or bare executable name (looked up on PATH).
+    Path(String),
+    /// A Windows registry App Paths entry, e.g. `chrome.exe`.
+    Registry(&'static str),
+}
+
+struct BrowserCandidate {
+    channel: Channel,
+    probe: Probe,
+}
+
+impl BrowserCandidate {
+    fn resolve(&self) -> Option<PathBuf> {
+        match &self.probe {
+            Probe::Path(candidate) => {
+                let path = PathBuf::from(candidate);
+                if path.is_absolute() {
+                    path.exists().then_some(path)
+                } else {
+                    which(candidate).ok()
+                }
+            }
+            Probe::Registry(exe_name) => registry_browser_path(*exe_name),
+        }
+    }
+}
 
-        for candidate in candidates {
-            let path = PathBuf::from(&candidate);
-            if path.is_absolute() {
+/// Builds the full, platform-specific, channel-preference-ordered list of places to
+/// look for a browser: unstable/Canary first (most likely a developer's deliberate
+/// choice), then Dev, then Beta, then Stable (registry lookup included, since that's
+/// where Stable's enterprise-managed installs register themselves), then other
+/// Chromium-based fallbacks that aren't tied to a specific channel.
+fn browser_candidates() -> Vec<BrowserCandidate> {
+    use Channel::{Beta, Canary, Dev, Stable};
+
+    let path = |channel: Channel, p: &str| BrowserCandidate {
+        channel,
+        probe: Probe::Path(p.to_string()),
+    };
+    let registry = |channel: Channel, exe: &'static str| BrowserCandidate {
+        channel,
+        probe: Probe::Registry(exe),
+    };
+
+    if cfg!(target_os = "windows") {
+        let program_files = std::env::var("ProgramFiles").unwrap_or_else(|_| r"C:\Program Files".to_string());
+        let program_files_x86 = std::env::var("ProgramFiles(x86)").unwrap_or_else(|_| r"C:\Program Files (x86)".to_string());
+        let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| r"C:\Users\Default\AppData\Local".to_string());
+
+        vec![
+            path(Canary, &format!(r"{}\Google\Chrome SxS\Application\chrome.exe", local_app_data)),
+            path(Dev, &format!(r"{}\Google\Chrome Dev\Application\chrome.exe", program_files)),
+            path(Dev, &format!(r"{}\Google\Chrome Dev\Application\chrome.exe", program_files_x86)),
+            path(Beta, &format!(r"{}\Google\Chrome Beta\Application\chrome.exe", program_files)),
+            path(Beta, &format!(r"{}\Google\Chrome Beta\Application\chrome.exe", program_files_x86)),
+            // Enterprise installs frequently live outside Program Files, so the
+            // registry's App Paths entries are checked alongside the Stable candidates.
+            registry(Stable, "chrome.exe"),
+            registry(Stable, "msedge.exe"),
+            registry(Stable, "chromium.exe"),
+            path(Stable, &format!(r"{}\Google\Chrome\Application\chrome.exe", program_files)),
+            path(Stable, &format!(r"{}\Google\Chrome\Application\chrome.exe", program_files_x86)),
+            path(Stable, &format!(r"{}\Microsoft\Edge Beta\Application\msedge.exe", program_files)),
+            path(Stable, &format!(r"{}\Microsoft\Edge Beta\Application\msedge.exe", program_files_x86)),
+            path(Stable, &format!(r"{}\Microsoft\Edge\Application\msedge.exe", program_files)),
+            path(Stable, &format!(r"{}\Microsoft\Edge\Application\msedge.exe", program_files_x86)),
+            // Standard PATH binaries, as a last resort.
+            path(Stable, "chrome.exe"),
+            path(Stable, "msedge.exe"),
+            path(Stable, "chromium.exe"),
+        ]
+    } else if cfg!(target_os = "macos") {
+        vec![
+            path(Canary, "/Applications/Google Chrome Canary.app/Contents/MacOS/Google Chrome Canary"),
+            path(Dev, "/Applications/Google Chrome Dev.app/Contents/MacOS/Google Chrome Dev"),
+            path(Beta, "/Applications/Google Chrome Beta.app/Contents/MacOS/Google Chrome Beta"),
+            path(Stable, "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome"),
+            path(Stable, "/Applications/Chromium.app/Contents/MacOS/Chromium"),
+            path(Stable, "/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge"),
+        ]
+    } else {
+        // Google doesn't ship a Canary channel for Linux; `google-chrome-unstable`
+        // is the Dev/unstable channel's actual package name.
+        vec![
+            path(Dev, "google-chrome-unstable"),
+            path(Beta, "google-chrome-beta"),
+            path(Stable, "google-chrome"),
+            path(Stable, "google-chrome-stable"),
+            path(Stable, "chromium"),
+            path(Stable, "chromium-browser"),
+        ]
+    }
+}
+
+/// Looks up a browser executable via the Windows registry's App Paths key
+/// (`SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\<exe>`), which is
+/// where enterprise-managed installs register themselves regardless of where
+/// they actually live on disk. Checks `HKEY_CURRENT_USER` before
+/// `HKEY_LOCAL_MACHINE`, matching how Windows itself resolves App Paths.
+#[cfg(target_os = "windows")]
+fn registry_browser_path(exe_name: &str) -> Option<PathBuf> {
+    use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+    use winreg::RegKey;
+
+    let key_path = format!(
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\{}",
+        exe_name
+    );
+
+    for hive in [HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE] {
+        let root = RegKey::predef(hive);
+        if let Ok(key) = root.open_subkey(&key_path) {
+            if let Ok(path) = key.get_value::<String, _>("") {
+                let path = PathBuf::from(path);
                 if path.exists() {
-                    return Ok(path);
+                    return Some(path);
                 }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(not(target_os = "windows"))]
+fn registry_browser_path(_exe_name: &str) -> Option<PathBuf> {
+    None
+}
+
+/// Resolves the real, non-ephemeral profile directory Chrome/Edge itself uses
+/// for the given channel, for `use_installed_profile`.
+fn installed_profile_dir(channel: Channel) -> Result<PathBuf, WebIntelError> {
+    let not_found = || {
+        WebIntelError::ProfileCreationFailure(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Could not determine the installed browser profile directory",
+        ))
+    };
+
+    if cfg!(target_os = "windows") {
+        let local_app_data = std::env::var("LOCALAPPDATA").map_err(|_| not_found())?;
+        let dir_name = match channel {
+            Channel::Stable => r"Google\Chrome",
+            Channel::Beta => r"Google\Chrome Beta",
+            Channel::Dev => r"Google\Chrome Dev",
+            Channel::Canary => r"Google\Chrome SxS",
+        };
+        Ok(PathBuf::from(local_app_data).join(dir_name).join("User Data"))
+    } else if cfg!(target_os = "macos") {
+        let home = dirs::home_dir().ok_or_else(not_found)?;
+        let dir_name = match channel {
+            Channel::Stable => "Google/Chrome",
+            Channel::Beta => "Google/Chrome Beta",
+            Channel::Dev => "Google/Chrome Dev",
+            Channel::Canary => "Google/Chrome Canary",
+        };
+        Ok(home.join("Library/Application Support").join(dir_name))
+    } else {
+        let home = dirs::home_dir().ok_or_else(not_found)?;
+        let dir_name = match channel {
+            Channel::Stable => "google-chrome",
+            Channel::Beta => "google-chrome-beta",
+            Channel::Dev => "google-chrome-unstable",
+            Channel::Canary => "google-chrome-unstable",
+        };
+        Ok(home.join(".config").join(dir_name))
+    }
+}
+
+/// Resolves the port to pass as `--remote-debugging-port`.
+///
+/// If the caller pinned a port, it's checked with a pre-bind probe so a port
+/// already in use is reported as `DebugPortInUse` rather than surfacing later
+/// as an opaque browser launch failure. Otherwise a free port is picked by
+/// asking the OS to bind port 0 and reading back what it assigned.
+fn resolve_debug_port(requested: Option<u16>) -> Result<u16, WebIntelError> {
+    match requested {
+        Some(port) => {
+            if check_port_available(port)? {
+                Ok(port)
             } else {
-                if let Ok(p) = which(&candidate) {
-                    return Ok(p);
+                Err(WebIntelError::DebugPortInUse(port))
+            }
+        }
+        None => {
+            // `bind("127.0.0.1:0")` can't meaningfully fail to find a free port on a
+            // normal machine, but retry a couple of times before giving up in case
+            // of a transient resource exhaustion, rather than propagating that as
+            // an unrelated IO error.
+            const ATTEMPTS: u32 = 3;
+            for _ in 0..ATTEMPTS {
+                if let Ok(listener) = TcpListener::bind("127.0.0.1:0") {
+                    if let Ok(addr) = listener.local_addr() {
+                        let port = addr.port();
+                        drop(listener);
+                        return Ok(port);
+                    }
                 }
             }
+            Err(WebIntelError::NoAvailablePorts)
         }
+    }
+}
 
-        Err(WebIntelError::BrowserNotFound)
+/// Returns `true` if `127.0.0.1:<port>` can be bound right now. There's an inherent
+/// TOCTOU gap between this check and the browser binding the port itself, but it's
+/// enough to catch the common case of a stale process already holding the port.
+fn check_port_available(port: u16) -> Result<bool, WebIntelError> {
+    match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => {
+            drop(listener);
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => Ok(false),
+        Err(e) => Err(WebIntelError::LaunchFailure(e)),
+    }
+}
+
+/// The subset of the `/json/version` response we care about.
+#[derive(Debug, Deserialize)]
+struct DevToolsVersion {
+    #[serde(rename = "webSocketDebuggerUrl")]
+    websocket_debugger_url: String,
+}
+
+/// Polls `http://127.0.0.1:<port>/json/version` until it responds with the
+/// browser-level DevTools WebSocket URL, or times out. This is the same
+/// discovery mechanism chromedriver uses, and it sidesteps parsing stderr.
+fn wait_for_websocket_url(
+    child: &mut Child,
+    port: u16,
+    timeout: Duration,
+) -> Result<String, WebIntelError> {
+    let endpoint = format!("http://127.0.0.1:{}/json/version", port);
+    let start = Instant::now();
+
+    while start.elapsed() < timeout {
+        if let Ok(Some(_status)) = child.try_wait() {
+            return Err(WebIntelError::LaunchFailure(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Browser process exited unexpectedly",
+            )));
+        }
+
+        if let Ok(version) = fetch_devtools_version(&endpoint) {
+            return Ok(version.websocket_debugger_url);
+        }
+
+        thread::sleep(Duration::from_millis(100));
     }
+
+    Err(WebIntelError::WebSocketUrlNotFound)
+}
+
+/// Fetches and parses a `/json/version` DevTools endpoint.
+fn fetch_devtools_version(endpoint: &str) -> Result<DevToolsVersion, WebIntelError> {
+    ureq::get(endpoint)
+        .call()
+        .map_err(|_| WebIntelError::WebSocketUrlNotFound)?
+        .into_json::<DevToolsVersion>()
+        .map_err(|_| WebIntelError::WebSocketUrlNotFound)
 }
 
 /// A wrapper around the profile directory to handle ephemeral vs persistent storage.
@@ -387,41 +683,110 @@ impl UserDataDir {
     }
 }
 
-/// A handle to the running browser process.
-///
-/// When this struct is dropped, the ephemeral user data directory (if used) is cleaned up,
-/// but the browser process itself is NOT automatically killed unless you explicitly do so,
-/// though idiomatic Rust wrappers often kill child processes on drop.
+/// A handle to a browser, either one this crate spawned or one it merely
+/// attached to via `connect`.
 ///
-/// Note: The standard `std::process::Child` does NOT kill on drop.
-/// However, for an "Agent" workflow, it might be desirable to kill the browser when the handle is dropped.
-/// Let's implement kill on drop for safety, to prevent zombie browser processes.
+/// When this struct is dropped, the ephemeral user data directory (if used) is
+/// cleaned up. The browser process itself is killed on drop, but only if this
+/// handle is the one that spawned it: a handle obtained via `connect` never
+/// owned the process, so killing it on drop would yank the browser out from
+/// under whatever orchestrator actually started it.
 pub struct BrowserHandle {
-    process: Child,
+    process: Option<Child>,
     websocket_url: String,
-    // Kept alive to prevent deletion until Drop
-    _user_data_dir: UserDataDir,
+    // Kept alive to prevent deletion until Drop. `None` for connected handles,
+    // since we don't know about (and don't own) the remote browser's profile.
+    _user_data_dir: Option<UserDataDir>,
+    owns_process: bool,
+    // Memoized by `session()`: each `Session` opens its own socket and spawns a
+    // reader thread that lives for as long as the `Arc` is held, so repeated
+    // calls must hand back the same one rather than leaking a new one each time.
+    session: Mutex<Option<Arc<Session>>>,
 }
 
 impl BrowserHandle {
+    /// Attaches to an already-running browser instead of spawning a new one.
+    ///
+    /// `url` may be either a DevTools WebSocket URL
+    /// (`ws://host:port/devtools/browser/<id>`) or an HTTP base
+    /// (`http://host:port`), in which case the WebSocket URL is resolved via
+    /// that host's `/json/version` endpoint. The returned handle's `Drop`
+    /// does not kill the browser process, since this handle never spawned it.
+    pub fn connect(url: impl AsRef<str>) -> Result<Self, WebIntelError> {
+        let url = url.as_ref();
+        let websocket_url = if url.starts_with("ws://") || url.starts_with("wss://") {
+            url.to_string()
+        } else {
+            let endpoint = format!("{}/json/version", url.trim_end_matches('/'));
+            fetch_devtools_version(&endpoint)?.websocket_debugger_url
+        };
+
+        Ok(Self {
+            process: None,
+            websocket_url,
+            _user_data_dir: None,
+            owns_process: false,
+            session: Mutex::new(None),
+        })
+    }
+
     /// Returns the DevTools WebSocket URL.
     pub fn websocket_url(&self) -> &str {
         &self.websocket_url
     }
 
-    /// Access the underlying Child process.
-    pub fn process(&mut self) -> &mut Child {
-        &mut self.process
+    /// Access the underlying Child process, if this handle spawned one.
+    /// Returns `None` for a handle obtained via `connect`.
+    pub fn process(&mut self) -> Option<&mut Child> {
+        self.process.as_mut()
+    }
+
+    /// Returns `true` if this handle spawned the browser process (and will
+    /// therefore kill it on drop), as opposed to having attached to one via
+    /// `connect`.
+    pub fn owns_process(&self) -> bool {
+        self.owns_process
+    }
+
+    /// Returns a CDP session over this browser's DevTools WebSocket, so it can
+    /// actually be driven — opening tabs, navigating, evaluating JS, and so on.
+    ///
+    /// The session is opened lazily on first call and memoized: every call
+    /// after the first returns the same `Arc<Session>` rather than opening a
+    /// new socket and reader thread each time.
+    pub fn session(&self) -> Result<Arc<Session>, WebIntelError> {
+        let mut session = self.session.lock().unwrap();
+        if let Some(session) = session.as_ref() {
+            return Ok(session.clone());
+        }
+        let new_session = Session::connect(&self.websocket_url)?;
+        *session = Some(new_session.clone());
+        Ok(new_session)
     }
 }
 
 impl Drop for BrowserHandle {
     fn drop(&mut self) {
+        // This handle is the one that opened (and memoized) the session, if any,
+        // so it's the one responsible for tearing it down: a `Session`'s reader
+        // thread otherwise holds its own `Arc` and runs until the browser closes
+        // the socket, regardless of how many external `Arc<Session>` clones remain.
+        if let Ok(mut session) = self.session.lock() {
+            if let Some(session) = session.take() {
+                session.close();
+            }
+        }
+
+        if !self.owns_process {
+            return;
+        }
         // We attempt to kill the browser process when the handle is dropped.
         // This ensures that we don't leave stray browser instances running
         // after the agent finishes or crashes.
-        let _ = self.process.kill();
-        let _ = self.process.wait();
-        debug!("Browser process terminated.");
+        if let Some(process) = self.process.as_mut() {
+            let _ = process.kill();
+            let _ = process.wait();
+            debug!("Browser process terminated.");
+        }
     }
 }
@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+use crate::error::WebIntelError;
+
+const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+type Socket = WebSocket<MaybeTlsStream<std::net::TcpStream>>;
+
+/// A CDP event dispatched to subscribers: the method name, its params, and
+/// the `sessionId` it was scoped to (present for anything routed to a
+/// specific `Tab` under flat session mode; absent for browser-level events).
+#[derive(Debug, Clone)]
+pub struct CdpEvent {
+    pub method: String,
+    pub params: Value,
+    pub session_id: Option<String>,
+}
+
+/// A JSON-RPC session over a browser's DevTools WebSocket connection.
+///
+/// Mirrors headless_chrome's `Transport`: a background reader thread owns the
+/// socket, correlates command responses to the caller that issued them by
+/// `id`, and fans unsolicited CDP events out to anyone subscribed via
+/// `subscribe_events`. This is the layer `Tab` is built on; most callers want
+/// `Session::new_tab` rather than talking to the session directly.
+pub struct Session {
+    socket: Mutex<Socket>,
+    next_id: AtomicU64,
+    pending: Mutex<HashMap<u64, Sender<Value>>>,
+    subscribers: Mutex<Vec<Sender<CdpEvent>>>,
+    call_timeout: Duration,
+    // Checked by the reader thread's poll loop. The thread itself holds a strong
+    // `Arc<Session>` for as long as it runs, so the session otherwise never tears
+    // down on its own no matter how many external `Arc` handles get dropped.
+    shutdown: AtomicBool,
+}
+
+impl Session {
+    /// Opens a WebSocket connection to `websocket_url` and starts the
+    /// background reader thread. `websocket_url` is typically
+    /// `BrowserHandle::websocket_url()`.
+    pub fn connect(websocket_url: &str) -> Result<Arc<Self>, WebIntelError> {
+        Self::connect_with_timeout(websocket_url, DEFAULT_CALL_TIMEOUT)
+    }
+
+    /// Like `connect`, but with a custom per-call timeout instead of the default 30s.
+    pub fn connect_with_timeout(
+        websocket_url: &str,
+        call_timeout: Duration,
+    ) -> Result<Arc<Self>, WebIntelError> {
+        let (mut socket, _response) = tungstenite::connect(websocket_url)
+            .map_err(|e| WebIntelError::SessionFailure(e.to_string()))?;
+
+        // The reader thread polls rather than blocking forever on read_message,
+        // so that the socket mutex doesn't starve callers trying to write.
+        set_read_timeout(&mut socket, READ_POLL_INTERVAL);
+
+        let session = Arc::new(Self {
+            socket: Mutex::new(socket),
+            next_id: AtomicU64::new(1),
+            pending: Mutex::new(HashMap::new()),
+            subscribers: Mutex::new(Vec::new()),
+            call_timeout,
+            shutdown: AtomicBool::new(false),
+        });
+
+        let reader = session.clone();
+        thread::spawn(move || reader.read_loop());
+
+        Ok(session)
+    }
+
+    /// Tears this session down: signals the reader thread to stop and sends a
+    /// WebSocket close frame. Safe to call more than once.
+    ///
+    /// Because the reader thread holds its own `Arc<Session>` for as long as it
+    /// runs, a `Session` otherwise lives until the browser closes the socket (or
+    /// the socket errors), regardless of how many external `Arc<Session>`
+    /// references get dropped — this is what actually breaks that cycle.
+    pub fn close(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        let mut socket = self.socket.lock().unwrap();
+        let _ = socket.close(None);
+    }
+
+    /// Opens a new blank tab and returns a handle to it, attached in CDP's
+    /// "flat" session mode so its commands/events are scoped by `sessionId`.
+    pub fn new_tab(self: &Arc<Self>) -> Result<Tab, WebIntelError> {
+        #[derive(Deserialize)]
+        struct CreateTargetResult {
+            #[serde(rename = "targetId")]
+            target_id: String,
+        }
+        #[derive(Deserialize)]
+        struct AttachToTargetResult {
+            #[serde(rename = "sessionId")]
+            session_id: String,
+        }
+
+        let created: CreateTargetResult =
+            self.call("Target.createTarget", json!({ "url": "about:blank" }))?;
+        let attached: AttachToTargetResult = self.call(
+            "Target.attachToTarget",
+            json!({ "targetId": created.target_id, "flatten": true }),
+        )?;
+
+        // Subscribe before issuing any commands on the tab (including Page.enable),
+        // so a `Page.loadEventFired` fired in the gap between a later `navigate()`
+        // and a subsequent `wait_for_navigation()` is queued on this receiver
+        // rather than silently missed.
+        let events = self.subscribe_events();
+
+        let tab = Tab {
+            session: self.clone(),
+            target_id: created.target_id,
+            session_id: attached.session_id,
+            events: Mutex::new(events),
+        };
+
+        // Without Page.enable, the tab never emits Page.loadEventFired.
+        tab.call::<Value>("Page.enable", json!({}))?;
+
+        Ok(tab)
+    }
+
+    /// Subscribes to the stream of CDP events dispatched by the reader thread.
+    /// Each call returns an independent receiver; events are broadcast to all of them.
+    pub fn subscribe_events(&self) -> Receiver<CdpEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Sends a browser-level CDP command (no `sessionId`) and waits for its result.
+    pub fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, WebIntelError> {
+        self.call_scoped(method, params, None)
+    }
+
+    fn call_scoped<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+        session_id: Option<&str>,
+    ) -> Result<T, WebIntelError> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = mpsc::channel();
+        self.pending.lock().unwrap().insert(id, tx);
+
+        let mut payload = json!({ "id": id, "method": method, "params": params });
+        if let Some(sid) = session_id {
+            payload["sessionId"] = json!(sid);
+        }
+
+        {
+            let mut socket = self.socket.lock().unwrap();
+            socket
+                .write_message(Message::Text(payload.to_string()))
+                .map_err(|e| WebIntelError::SessionFailure(e.to_string()))?;
+        }
+
+        let result = rx.recv_timeout(self.call_timeout).map_err(|_| {
+            self.pending.lock().unwrap().remove(&id);
+            WebIntelError::CallTimeout(method.to_string())
+        })?;
+
+        serde_json::from_value(result).map_err(|e| WebIntelError::SessionFailure(e.to_string()))
+    }
+
+    fn read_loop(self: Arc<Self>) {
+        loop {
+            if self.shutdown.load(Ordering::SeqCst) {
+                debug!("CDP session reader thread stopping (close() called)");
+                break;
+            }
+
+            let message = {
+                let mut socket = self.socket.lock().unwrap();
+                socket.read_message()
+            };
+
+            match message {
+                Ok(Message::Text(text)) => self.dispatch(&text),
+                Ok(Message::Close(_)) => {
+                    debug!("CDP session WebSocket closed");
+                    break;
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(ref e))
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    // Just our poll interval elapsing with nothing to read.
+                    thread::sleep(READ_POLL_INTERVAL);
+                }
+                Err(e) => {
+                    warn!("CDP session reader thread exiting: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn dispatch(&self, text: &str) {
+        let value: Value = match serde_json::from_str(text) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Failed to parse CDP message: {}", e);
+                return;
+            }
+        };
+
+        if let Some(id) = value.get("id").and_then(Value::as_u64) {
+            if let Some(sender) = self.pending.lock().unwrap().remove(&id) {
+                let result = value.get("result").cloned().unwrap_or(Value::Null);
+                let _ = sender.send(result);
+            }
+            return;
+        }
+
+        if let Some(method) = value.get("method").and_then(Value::as_str) {
+            let event = CdpEvent {
+                method: method.to_string(),
+                params: value.get("params").cloned().unwrap_or(Value::Null),
+                session_id: value
+                    .get("sessionId")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            };
+            let mut subscribers = self.subscribers.lock().unwrap();
+            subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        // Mainly defensive: by the time this runs, the reader thread's own
+        // `Arc<Session>` clone must already be gone, which happens once `close()`
+        // has stopped it or the socket has closed or errored. Cheap to repeat and
+        // harmless if so.
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Ok(mut socket) = self.socket.lock() {
+            let _ = socket.close(None);
+        }
+    }
+}
+
+fn set_read_timeout(socket: &mut Socket, timeout: Duration) {
+    let stream = match socket.get_ref() {
+        MaybeTlsStream::Plain(s) => s,
+        _ => return,
+    };
+    if let Err(e) = stream.set_read_timeout(Some(timeout)) {
+        warn!("Failed to set CDP socket read timeout: {}", e);
+    }
+}
+
+/// A single tab/page within a `Session`, scoped by CDP's flat `sessionId` mode.
+pub struct Tab {
+    session: Arc<Session>,
+    target_id: String,
+    session_id: String,
+    // Subscribed at tab creation (before any navigation can happen), so events
+    // fired between `navigate()` returning and a later `wait_for_navigation()`
+    // call are queued here instead of being dropped.
+    events: Mutex<Receiver<CdpEvent>>,
+}
+
+impl Tab {
+    /// The CDP target ID backing this tab.
+    pub fn target_id(&self) -> &str {
+        &self.target_id
+    }
+
+    fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, WebIntelError> {
+        self.session.call_scoped(method, params, Some(&self.session_id))
+    }
+
+    /// Navigates the tab to `url`. Does not wait for the page to finish loading;
+    /// pair with `wait_for_navigation` if you need that.
+    pub fn navigate(&self, url: &str) -> Result<(), WebIntelError> {
+        self.call::<Value>("Page.navigate", json!({ "url": url }))?;
+        Ok(())
+    }
+
+    /// Blocks until this tab's next `Page.loadEventFired` event, or until the
+    /// session's per-call timeout elapses.
+    ///
+    /// Reads from the event receiver subscribed when this `Tab` was created, so
+    /// a `Page.loadEventFired` that fires between `navigate()` returning and this
+    /// call (common for fast or cached loads) is queued rather than missed.
+    pub fn wait_for_navigation(&self) -> Result<(), WebIntelError> {
+        let events = self.events.lock().unwrap();
+        let deadline = Instant::now() + self.session.call_timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(WebIntelError::CallTimeout("Page.loadEventFired".to_string()));
+            }
+            match events.recv_timeout(remaining) {
+                Ok(event)
+                    if event.method == "Page.loadEventFired"
+                        && event.session_id.as_deref() == Some(self.session_id.as_str()) =>
+                {
+                    return Ok(());
+                }
+                Ok(_) => continue,
+                Err(_) => return Err(WebIntelError::CallTimeout("Page.loadEventFired".to_string())),
+            }
+        }
+    }
+
+    /// Evaluates `js` in the tab's main frame and returns its result value.
+    pub fn evaluate(&self, js: &str) -> Result<Value, WebIntelError> {
+        let result: Value = self.call(
+            "Runtime.evaluate",
+            json!({ "expression": js, "returnByValue": true }),
+        )?;
+        Ok(result
+            .get("result")
+            .and_then(|r| r.get("value"))
+            .cloned()
+            .unwrap_or(Value::Null))
+    }
+
+    /// Captures a PNG screenshot of the tab and returns the decoded image bytes.
+    pub fn capture_screenshot(&self) -> Result<Vec<u8>, WebIntelError> {
+        let result: Value = self.call("Page.captureScreenshot", json!({}))?;
+        let data = result
+            .get("data")
+            .and_then(Value::as_str)
+            .ok_or_else(|| WebIntelError::SessionFailure("response had no screenshot data".to_string()))?;
+
+        base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| WebIntelError::SessionFailure(e.to_string()))
+    }
+}
+
+impl Drop for Tab {
+    fn drop(&mut self) {
+        // Target.closeTarget is a browser-level command, sent without a sessionId.
+        // Without this, every short-lived `Tab` would leak an actual open tab for
+        // the lifetime of the browser process.
+        let result: Result<Value, WebIntelError> = self
+            .session
+            .call("Target.closeTarget", json!({ "targetId": self.target_id }));
+        if let Err(e) = result {
+            warn!("Failed to close CDP target {}: {}", self.target_id, e);
+        }
+    }
+}